@@ -9,57 +9,139 @@ use syn::{
 
 const GENERICS_ERROR: &str = "`#[derive(FromRequest)] doesn't support generics";
 
-pub(crate) fn expand(item: syn::ItemStruct) -> syn::Result<TokenStream> {
-    let syn::ItemStruct {
+pub(crate) fn expand(item: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let syn::DeriveInput {
         attrs,
         ident,
         generics,
-        fields,
-        semi_token: _,
+        data,
         vis: _,
-        struct_token: _,
     } = item;
 
-    if !generics.params.is_empty() {
-        return Err(syn::Error::new_spanned(generics, GENERICS_ERROR));
+    let FromRequestAttrs {
+        via,
+        rejection,
+        validate,
+        default,
+    } = parse_attrs(&attrs)?;
+    let rejection = rejection.map(|(_, path)| path);
+
+    if let Some((validate, _)) = validate {
+        return Err(syn::Error::new_spanned(
+            validate,
+            "`#[from_request(validate = ...)]` cannot be used on the container, \
+            only on a field",
+        ));
     }
 
-    if let Some(where_clause) = generics.where_clause {
-        return Err(syn::Error::new_spanned(where_clause, GENERICS_ERROR));
+    if let Some(default) = default {
+        return Err(syn::Error::new_spanned(
+            default,
+            "`#[from_request(default)]` cannot be used on the container, only on a field",
+        ));
     }
 
-    let FromRequestAttrs { via } = parse_attrs(&attrs)?;
+    match data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => {
+            if let Some((_, path)) = via {
+                // `via` delegates to another extractor's `FromRequest<B>` impl, which
+                // doesn't know about `Self`'s generics, so we keep the existing
+                // restriction here.
+                if !generics.params.is_empty() {
+                    return Err(syn::Error::new_spanned(generics, GENERICS_ERROR));
+                }
 
-    if let Some((_, path)) = via {
-        impl_by_extracting_all_at_once(ident, fields, path)
-    } else {
-        impl_by_extracting_each_field(ident, fields)
+                if let Some(where_clause) = generics.where_clause {
+                    return Err(syn::Error::new_spanned(where_clause, GENERICS_ERROR));
+                }
+
+                impl_by_extracting_all_at_once(ident, fields, path, rejection)
+            } else {
+                impl_struct_by_extracting_each_field(ident, fields, generics, rejection)
+            }
+        }
+        syn::Data::Enum(data_enum) => {
+            if !generics.params.is_empty() {
+                return Err(syn::Error::new_spanned(generics, GENERICS_ERROR));
+            }
+
+            if let Some(where_clause) = generics.where_clause {
+                return Err(syn::Error::new_spanned(where_clause, GENERICS_ERROR));
+            }
+
+            if let Some((via, _)) = via {
+                return Err(syn::Error::new_spanned(
+                    via,
+                    "`#[from_request(via(...))]` cannot be used on enums",
+                ));
+            }
+
+            impl_enum_by_trying_each_variant(ident, data_enum, rejection)
+        }
+        syn::Data::Union(_) => Err(syn::Error::new_spanned(
+            ident,
+            "`#[derive(FromRequest)]` doesn't support unions",
+        )),
     }
 }
 
-fn impl_by_extracting_each_field(
+fn impl_struct_by_extracting_each_field(
     ident: syn::Ident,
     fields: syn::Fields,
+    generics: syn::Generics,
+    rejection: Option<syn::Path>,
 ) -> syn::Result<TokenStream> {
+    let needs_validation = fields_need_validation(&fields)?;
+    let field_bounds = from_request_bounds(&fields)?;
+
     let extract_fields = match fields {
-        syn::Fields::Named(fields) => extract_fields(fields.named.iter())?,
-        syn::Fields::Unnamed(fields) => extract_fields(fields.unnamed.iter())?,
+        syn::Fields::Named(fields) => extract_fields(fields.named.iter(), rejection.is_some())?,
+        syn::Fields::Unnamed(fields) => extract_fields(fields.unnamed.iter(), rejection.is_some())?,
         syn::Fields::Unit => Default::default(),
     };
 
+    let rejection_ty = rejection_type(&rejection);
+    let validation_helper = validation_helper(needs_validation, &rejection);
+
+    // `B` is the trait's own generic param, not one of `Self`'s, so it's added
+    // to the struct's existing generics (if any) to build `impl_generics`,
+    // while `ty_generics`/`where_clause` come from the struct's generics alone.
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let ty_generics = quote! { #ty_generics };
+    let extra_where_predicates = where_clause.map(|where_clause| where_clause.predicates.clone());
+
+    // Lifetime params must come before type/const params in `impl<..>`, so `B`
+    // has to be inserted after the struct's own lifetimes rather than at the
+    // front; `struct Wrapper<'a, T>` would otherwise expand to the ill-formed
+    // `impl<B, 'a, T> ...`.
+    let mut generics_with_body = generics;
+    let first_non_lifetime = generics_with_body
+        .params
+        .iter()
+        .position(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+        .unwrap_or(generics_with_body.params.len());
+    generics_with_body
+        .params
+        .insert(first_non_lifetime, syn::parse_quote!(B));
+    let (impl_generics, _, _) = generics_with_body.split_for_impl();
+
     Ok(quote! {
         #[::axum::async_trait]
-        impl<B> ::axum::extract::FromRequest<B> for #ident
+        impl #impl_generics ::axum::extract::FromRequest<B> for #ident #ty_generics
         where
             B: ::axum::body::HttpBody + ::std::marker::Send + 'static,
             B::Data: ::std::marker::Send,
             B::Error: ::std::convert::Into<::axum::BoxError>,
+            #(#field_bounds,)*
+            #extra_where_predicates
         {
-            type Rejection = ::axum::response::Response;
+            type Rejection = #rejection_ty;
 
             async fn from_request(
                 req: &mut ::axum::extract::RequestParts<B>,
             ) -> ::std::result::Result<Self, Self::Rejection> {
+                #validation_helper
+
                 ::std::result::Result::Ok(Self {
                     #(#extract_fields)*
                 })
@@ -68,14 +150,281 @@ fn impl_by_extracting_each_field(
     })
 }
 
-fn extract_fields<'a, I>(fields: I) -> syn::Result<Vec<TokenStream>>
+// `Self`'s own generics (if any) give the each-field expansion's `B` no way
+// to know each field actually implements `FromRequest<B>` — e.g. `Json<T>:
+// FromRequest<B>` needs `T: DeserializeOwned`, which only the concrete
+// caller knows. Since a derive-generated impl is the only place these bounds
+// can be added (the user has no access to the generated `impl` block), we
+// add one `FieldTy: FromRequest<B>` predicate per field (or `Path<FieldTy>:
+// FromRequest<B>` when the field uses `via(Path)`) to the where-clause.
+fn from_request_bounds(fields: &syn::Fields) -> syn::Result<Vec<TokenStream>> {
+    let fields: Vec<&syn::Field> = match fields {
+        syn::Fields::Named(fields) => fields.named.iter().collect(),
+        syn::Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        syn::Fields::Unit => Vec::new(),
+    };
+
+    fields
+        .into_iter()
+        .map(|field| {
+            let FromRequestAttrs { via, .. } = parse_attrs(&field.attrs)?;
+            let ty = &field.ty;
+            Ok(if let Some((_, path)) = via {
+                quote! { #path<#ty>: ::axum::extract::FromRequest<B> }
+            } else {
+                quote! { #ty: ::axum::extract::FromRequest<B> }
+            })
+        })
+        .collect()
+}
+
+fn fields_need_validation(fields: &syn::Fields) -> syn::Result<bool> {
+    let fields: Vec<&syn::Field> = match fields {
+        syn::Fields::Named(fields) => fields.named.iter().collect(),
+        syn::Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        syn::Fields::Unit => Vec::new(),
+    };
+
+    for field in fields {
+        let FromRequestAttrs { validate, .. } = parse_attrs(&field.attrs)?;
+        if validate.is_some() {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+// A small local helper trait, defined inside the generated `from_request`
+// body so it can't collide with another derive in the same module, that
+// normalizes the two shapes `#[from_request(validate = ...)]` predicates can
+// take (`fn(&T) -> bool` and `fn(&T) -> Result<(), E: IntoResponse>`) straight
+// into `Self::Rejection`.
+//
+// Without a container-level `rejection(...)` override, `Self::Rejection` is
+// `Response`, so a failed `bool` predicate is turned into a canned 422 and a
+// failed `Result<(), E>` predicate via `E`'s own `IntoResponse` impl.
+//
+// With an override, we route straight into the user's rejection type instead
+// of detouring through `Response` (which would otherwise force the user to
+// additionally implement `From<Response>`, on top of `From<FieldRejection>`
+// for every field): a `Result<(), E>` predicate only needs `E: Into<Rejection>`,
+// the same shape already required for the container's `rejection(...)` to
+// subsume each field's own rejection; a failed `bool` predicate carries no
+// error value of its own, so it falls back to `Rejection::default()`.
+fn validation_helper(needed: bool, rejection: &Option<syn::Path>) -> TokenStream {
+    if !needed {
+        return quote! {};
+    }
+
+    if let Some(rejection) = rejection {
+        quote! {
+            #[allow(non_camel_case_types)]
+            trait __AxumFromRequestValidationResult {
+                fn __axum_into_validation_result(self) -> ::std::result::Result<(), #rejection>;
+            }
+
+            impl __AxumFromRequestValidationResult for bool {
+                fn __axum_into_validation_result(self) -> ::std::result::Result<(), #rejection> {
+                    if self {
+                        ::std::result::Result::Ok(())
+                    } else {
+                        ::std::result::Result::Err(::std::default::Default::default())
+                    }
+                }
+            }
+
+            impl<E> __AxumFromRequestValidationResult for ::std::result::Result<(), E>
+            where
+                E: ::std::convert::Into<#rejection>,
+            {
+                fn __axum_into_validation_result(self) -> ::std::result::Result<(), #rejection> {
+                    self.map_err(::std::convert::Into::into)
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[allow(non_camel_case_types)]
+            trait __AxumFromRequestValidationResult {
+                fn __axum_into_validation_result(
+                    self,
+                ) -> ::std::result::Result<(), ::axum::response::Response>;
+            }
+
+            impl __AxumFromRequestValidationResult for bool {
+                fn __axum_into_validation_result(
+                    self,
+                ) -> ::std::result::Result<(), ::axum::response::Response> {
+                    if self {
+                        ::std::result::Result::Ok(())
+                    } else {
+                        ::std::result::Result::Err(::axum::response::IntoResponse::into_response(
+                            (
+                                ::axum::http::StatusCode::UNPROCESSABLE_ENTITY,
+                                "validation failed",
+                            ),
+                        ))
+                    }
+                }
+            }
+
+            impl<E> __AxumFromRequestValidationResult for ::std::result::Result<(), E>
+            where
+                E: ::axum::response::IntoResponse,
+            {
+                fn __axum_into_validation_result(
+                    self,
+                ) -> ::std::result::Result<(), ::axum::response::Response> {
+                    self.map_err(::axum::response::IntoResponse::into_response)
+                }
+            }
+        }
+    }
+}
+
+// The rejection type for the "extract each field" expansion: the user's
+// `#[from_request(rejection(...))]` override if given, otherwise the usual
+// blanket `Response`.
+fn rejection_type(rejection: &Option<syn::Path>) -> TokenStream {
+    if let Some(rejection) = rejection {
+        quote! { #rejection }
+    } else {
+        quote! { ::axum::response::Response }
+    }
+}
+
+// Mirrors `derive_more`'s enum expansion for `From`: each variant gets its own
+// independent extraction logic and we try them in declaration order, falling
+// through to the next variant on rejection.
+//
+// Since the request body can only be taken once, only the first
+// body-consuming variant that is attempted can ever succeed; later variants
+// that also try to consume the body will simply fail to extract it. Variants
+// that only look at parts (headers, extensions, etc) can be retried freely,
+// but mixing multiple body-consuming variants only makes sense if they're
+// mutually exclusive in practice (e.g. `Json<T>` vs `Form<T>` inspecting
+// `Content-Type` before touching the body).
+fn impl_enum_by_trying_each_variant(
+    ident: syn::Ident,
+    data: syn::DataEnum,
+    rejection: Option<syn::Path>,
+) -> syn::Result<TokenStream> {
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "cannot derive `FromRequest` for enums with no variants",
+        ));
+    }
+
+    let last_index = data.variants.len() - 1;
+    let has_custom_rejection = rejection.is_some();
+
+    let needs_validation = data
+        .variants
+        .iter()
+        .try_fold(false, |acc, variant| -> syn::Result<bool> {
+            Ok(acc || fields_need_validation(&variant.fields)?)
+        })?;
+
+    let attempts = data
+        .variants
+        .into_iter()
+        .enumerate()
+        .map(|(index, variant)| {
+            let variant_ident = variant.ident;
+
+            let construct_variant = match variant.fields {
+                syn::Fields::Named(fields) => {
+                    let extract_fields = extract_fields(fields.named.iter(), has_custom_rejection)?;
+                    quote! { #ident::#variant_ident { #(#extract_fields)* } }
+                }
+                syn::Fields::Unnamed(fields) => {
+                    let extract_fields =
+                        extract_fields(fields.unnamed.iter(), has_custom_rejection)?;
+                    // `extract_fields` always prefixes each field with its member
+                    // (`0: { .. }`, `1: { .. }`), which is only valid struct-literal
+                    // syntax; `Enum::Variant(0: .., 1: ..)` doesn't parse. Braces
+                    // work for tuple variants too.
+                    quote! { #ident::#variant_ident { #(#extract_fields)* } }
+                }
+                syn::Fields::Unit => {
+                    return Err(syn::Error::new_spanned(
+                        variant_ident,
+                        "`#[derive(FromRequest)]` doesn't support unit variants; \
+                         each variant must wrap the extractor(s) to try",
+                    ))
+                }
+            };
+
+            if index == last_index {
+                // The last variant is our fallback: let its rejection (if any)
+                // propagate as the overall rejection instead of being discarded.
+                Ok(quote! {
+                    return ::std::result::Result::Ok(#construct_variant);
+                })
+            } else {
+                Ok(quote! {
+                    let attempt: ::std::result::Result<Self, Self::Rejection> = async {
+                        ::std::result::Result::Ok(#construct_variant)
+                    }
+                    .await;
+
+                    if let ::std::result::Result::Ok(value) = attempt {
+                        return ::std::result::Result::Ok(value);
+                    }
+                })
+            }
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let rejection_ty = rejection_type(&rejection);
+    let validation_helper = validation_helper(needs_validation, &rejection);
+
+    Ok(quote! {
+        #[::axum::async_trait]
+        impl<B> ::axum::extract::FromRequest<B> for #ident
+        where
+            B: ::axum::body::HttpBody + ::std::marker::Send + 'static,
+            B::Data: ::std::marker::Send,
+            B::Error: ::std::convert::Into<::axum::BoxError>,
+        {
+            type Rejection = #rejection_ty;
+
+            async fn from_request(
+                req: &mut ::axum::extract::RequestParts<B>,
+            ) -> ::std::result::Result<Self, Self::Rejection> {
+                #validation_helper
+
+                #(#attempts)*
+
+                ::std::unreachable!("the last variant always returns")
+            }
+        }
+    })
+}
+
+fn extract_fields<'a, I>(fields: I, has_custom_rejection: bool) -> syn::Result<Vec<TokenStream>>
 where
     I: Iterator<Item = &'a syn::Field>,
 {
     fields
         .enumerate()
         .map(|(index, field)| {
-            let FromRequestAttrs { via } = parse_attrs(&field.attrs)?;
+            let FromRequestAttrs {
+                via,
+                rejection,
+                validate,
+                default,
+            } = parse_attrs(&field.attrs)?;
+
+            if let Some((rejection, _)) = rejection {
+                return Err(syn::Error::new_spanned(
+                    rejection,
+                    "`#[from_request(rejection(...))]` cannot be used on a field, \
+                    only on the container",
+                ));
+            }
 
             let member = if let Some(ident) = &field.ident {
                 quote! { #ident }
@@ -100,12 +449,67 @@ where
                 }
             };
 
-            Ok(quote_spanned! {ty_span=>
-                #member: {
+            // With a container-level `rejection(...)` override we keep the field's
+            // own rejection type and convert it with `From`, so a single
+            // strongly-typed error enum can implement `From` for each field's
+            // rejection. Without an override we collapse straight to `Response`,
+            // same as before.
+            let map_err = if has_custom_rejection {
+                quote! { ::std::convert::From::from }
+            } else {
+                quote! { ::axum::response::IntoResponse::into_response }
+            };
+
+            // After a successful extraction, run the field's `validate`
+            // predicate (if any) against the extracted value and bail out
+            // with a rejection before it's moved into the struct.
+            //
+            // The helper trait above already normalizes the predicate's own
+            // failure type (a `bool` or a `Result<(), E>`, with `E: IntoResponse`
+            // or, under a `rejection(...)` override, `E: Into<Rejection>`)
+            // straight into `Self::Rejection`, so the failure can be
+            // propagated as-is.
+            let validate_block = if let Some((validate, expr)) = &validate {
+                let span = validate.span();
+                quote_spanned! {span=>
+                    match __AxumFromRequestValidationResult::__axum_into_validation_result(
+                        (#expr)(&__value),
+                    ) {
+                        ::std::result::Result::Ok(()) => {}
+                        ::std::result::Result::Err(__rejection) => {
+                            return ::std::result::Result::Err(__rejection);
+                        }
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            // `#[from_request(default)]` swallows the extraction's rejection and
+            // substitutes `Default::default()` instead, so several independently
+            // optional extractors can be collected in one derive without each
+            // becoming `Option<T>`.
+            let extraction = if default.is_some() {
+                quote! {
+                    match ::axum::extract::FromRequest::from_request(req).await.map(#into_inner) {
+                        ::std::result::Result::Ok(__value) => __value,
+                        ::std::result::Result::Err(_) => ::std::default::Default::default(),
+                    }
+                }
+            } else {
+                quote! {
                     ::axum::extract::FromRequest::from_request(req)
                         .await
                         .map(#into_inner)
-                        .map_err(::axum::response::IntoResponse::into_response)?
+                        .map_err(#map_err)?
+                }
+            };
+
+            Ok(quote_spanned! {ty_span=>
+                #member: {
+                    let __value = #extraction;
+                    #validate_block
+                    __value
                 },
             })
         })
@@ -116,6 +520,7 @@ fn impl_by_extracting_all_at_once(
     ident: syn::Ident,
     fields: syn::Fields,
     path: syn::Path,
+    rejection: Option<syn::Path>,
 ) -> syn::Result<TokenStream> {
     let fields = match fields {
         syn::Fields::Named(fields) => fields.named.into_iter(),
@@ -124,7 +529,12 @@ fn impl_by_extracting_all_at_once(
     };
 
     for field in fields {
-        let FromRequestAttrs { via } = parse_attrs(&field.attrs)?;
+        let FromRequestAttrs {
+            via,
+            rejection,
+            validate,
+            default,
+        } = parse_attrs(&field.attrs)?;
         if let Some((via, _)) = via {
             return Err(syn::Error::new_spanned(
                 via,
@@ -132,10 +542,46 @@ fn impl_by_extracting_all_at_once(
                 together with `#[from_request(...)]` on the container",
             ));
         }
+        if let Some((rejection, _)) = rejection {
+            return Err(syn::Error::new_spanned(
+                rejection,
+                "`#[from_request(rejection(...))]` cannot be used on a field, \
+                only on the container",
+            ));
+        }
+        if let Some((validate, _)) = validate {
+            return Err(syn::Error::new_spanned(
+                validate,
+                "`#[from_request(validate = ...)]` on a field cannot be used \
+                together with `#[from_request(...)]` on the container",
+            ));
+        }
+        if let Some(default) = default {
+            return Err(syn::Error::new_spanned(
+                default,
+                "`#[from_request(default)]` on a field cannot be used \
+                together with `#[from_request(...)]` on the container",
+            ));
+        }
     }
 
     let path_span = path.span();
 
+    // Without an override the rejection is simply whatever `via`'s extractor
+    // already produces; with one we convert into it via `From`, same as the
+    // each-field expansion.
+    let rejection_ty = if let Some(rejection) = &rejection {
+        quote! { #rejection }
+    } else {
+        quote! { <#path<Self> as ::axum::extract::FromRequest<B>>::Rejection }
+    };
+
+    let map_err = if rejection.is_some() {
+        quote! { .map_err(::std::convert::From::from) }
+    } else {
+        quote! {}
+    };
+
     Ok(quote_spanned! {path_span=>
         #[::axum::async_trait]
         impl<B> ::axum::extract::FromRequest<B> for #ident
@@ -144,7 +590,7 @@ fn impl_by_extracting_all_at_once(
             B::Data: ::std::marker::Send,
             B::Error: ::std::convert::Into<::axum::BoxError>,
         {
-            type Rejection = <#path<Self> as ::axum::extract::FromRequest<B>>::Rejection;
+            type Rejection = #rejection_ty;
 
             async fn from_request(
                 req: &mut ::axum::extract::RequestParts<B>,
@@ -152,6 +598,7 @@ fn impl_by_extracting_all_at_once(
                 ::axum::extract::FromRequest::<B>::from_request(req)
                     .await
                     .map(|#path(inner)| inner)
+                    #map_err
             }
         }
     })
@@ -160,10 +607,16 @@ fn impl_by_extracting_all_at_once(
 #[derive(Debug, Default)]
 struct FromRequestAttrs {
     via: Option<(kw::via, syn::Path)>,
+    rejection: Option<(kw::rejection, syn::Path)>,
+    validate: Option<(kw::validate, syn::Expr)>,
+    default: Option<kw::default>,
 }
 
 mod kw {
     syn::custom_keyword!(via);
+    syn::custom_keyword!(rejection);
+    syn::custom_keyword!(validate);
+    syn::custom_keyword!(default);
 }
 
 fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<FromRequestAttrs> {
@@ -174,7 +627,19 @@ fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<FromRequestAttrs> {
 
     #[derive(Debug)]
     enum FromRequestAttr {
-        Via { via: kw::via, path: syn::Path },
+        Via {
+            via: kw::via,
+            path: syn::Path,
+        },
+        Rejection {
+            rejection: kw::rejection,
+            path: syn::Path,
+        },
+        Validate {
+            validate: kw::validate,
+            expr: syn::Expr,
+        },
+        Default(kw::default),
     }
 
     impl Parse for FromRequestAttr {
@@ -185,6 +650,19 @@ fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<FromRequestAttrs> {
                 let content;
                 syn::parenthesized!(content in input);
                 content.parse().map(|path| Self::Via { via, path })
+            } else if lh.peek(kw::rejection) {
+                let rejection = input.parse::<kw::rejection>()?;
+                let content;
+                syn::parenthesized!(content in input);
+                content
+                    .parse()
+                    .map(|path| Self::Rejection { rejection, path })
+            } else if lh.peek(kw::validate) {
+                let validate = input.parse::<kw::validate>()?;
+                input.parse::<Token![=]>()?;
+                input.parse().map(|expr| Self::Validate { validate, expr })
+            } else if lh.peek(kw::default) {
+                input.parse::<kw::default>().map(Self::Default)
             } else {
                 Err(lh.error())
             }
@@ -222,6 +700,36 @@ fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<FromRequestAttrs> {
                                 out.via = Some((via, path));
                             }
                         }
+                        FromRequestAttr::Rejection { rejection, path } => {
+                            if out.rejection.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    rejection,
+                                    "`rejection` specified more than once",
+                                ));
+                            } else {
+                                out.rejection = Some((rejection, path));
+                            }
+                        }
+                        FromRequestAttr::Validate { validate, expr } => {
+                            if out.validate.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    validate,
+                                    "`validate` specified more than once",
+                                ));
+                            } else {
+                                out.validate = Some((validate, expr));
+                            }
+                        }
+                        FromRequestAttr::Default(default) => {
+                            if out.default.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    default,
+                                    "`default` specified more than once",
+                                ));
+                            } else {
+                                out.default = Some(default);
+                            }
+                        }
                     }
                 }
             }
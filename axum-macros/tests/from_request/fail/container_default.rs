@@ -0,0 +1,15 @@
+use axum_macros::FromRequest;
+
+// `default` only makes sense per-field (it's a fallback for one failed
+// extraction); on the container there's no single extraction to fall back
+// from.
+//
+// Expected error: "`#[from_request(default)]` cannot be used on the
+// container, only on a field"
+#[derive(FromRequest)]
+#[from_request(default)]
+struct Extractor {
+    name: String,
+}
+
+fn main() {}
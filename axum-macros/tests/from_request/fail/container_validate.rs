@@ -0,0 +1,14 @@
+use axum_macros::FromRequest;
+
+// `validate` only makes sense per-field (it runs against one extracted
+// value); on the container it has nothing to validate against.
+//
+// Expected error: "`#[from_request(validate = ...)]` cannot be used on the
+// container, only on a field"
+#[derive(FromRequest)]
+#[from_request(validate = |_: &()| true)]
+struct Extractor {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use axum_macros::FromRequest;
+
+// There's no variant to construct, so there's nothing to try extracting.
+//
+// Expected error: "cannot derive `FromRequest` for enums with no variants"
+#[derive(FromRequest)]
+enum Extractor {}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use axum::extract::Json;
+use axum_macros::FromRequest;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Payload {
+    foo: String,
+}
+
+struct MyRejection;
+
+// `rejection(...)` picks the type for the whole derived impl's
+// `Self::Rejection`; it isn't something an individual field can override.
+//
+// Expected error: "`#[from_request(rejection(...))]` cannot be used on a
+// field, only on the container"
+#[derive(FromRequest)]
+struct Extractor {
+    #[from_request(rejection(MyRejection))]
+    json: Json<Payload>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use axum_macros::FromRequest;
+
+// Each variant must name the extractor(s) it wraps; a unit variant carries
+// no extractor to try.
+//
+// Expected error: "`#[derive(FromRequest)]` doesn't support unit variants;
+// each variant must wrap the extractor(s) to try"
+#[derive(FromRequest)]
+enum Extractor {
+    A(String),
+    B,
+}
+
+fn main() {}
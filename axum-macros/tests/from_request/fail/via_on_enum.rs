@@ -0,0 +1,14 @@
+use axum_macros::FromRequest;
+
+// `via` delegates the whole extraction to one other extractor, which only
+// makes sense for a single type, not for picking among an enum's variants.
+//
+// Expected error: "`#[from_request(via(...))]` cannot be used on enums"
+#[derive(FromRequest)]
+#[from_request(via(axum::extract::Extension))]
+enum Extractor {
+    A(String),
+    B(u32),
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use axum::extract::{FromRequest, RequestParts};
+use axum_macros::FromRequest;
+
+#[derive(Default)]
+struct ApiKey(Option<String>);
+
+#[axum::async_trait]
+impl<B> FromRequest<B> for ApiKey
+where
+    B: Send,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(Self(
+            req.headers()
+                .get("x-api-key")
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned),
+        ))
+    }
+}
+
+// `#[from_request(default)]` swallows a failed extraction and falls back to
+// `Default::default()`, so an optional extractor doesn't have to be wrapped
+// in `Option<T>`.
+#[derive(FromRequest)]
+struct Extractor {
+    #[from_request(default)]
+    api_key: ApiKey,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use axum::extract::{Form, Json};
+use axum_macros::FromRequest;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Payload {
+    foo: String,
+}
+
+// Tuple variants must expand with brace syntax (`Enum::Variant { 0: .., 1: .. }`),
+// not parens, since `extract_fields` always emits member-prefixed bindings.
+#[derive(FromRequest)]
+enum Extractor {
+    Json(Json<Payload>),
+    Form(Form<Payload>),
+}
+
+fn main() {}
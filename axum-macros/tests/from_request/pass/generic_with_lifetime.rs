@@ -0,0 +1,30 @@
+use axum::extract::{FromRequest, Json, RequestParts};
+use axum_macros::FromRequest;
+use std::marker::PhantomData;
+
+struct Marker<'a>(PhantomData<&'a ()>);
+
+#[axum::async_trait]
+impl<'a, B> FromRequest<B> for Marker<'a>
+where
+    B: Send,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request(_req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(Marker(PhantomData))
+    }
+}
+
+// A lifetime param declared before a type param must still produce a valid
+// `impl<'a, B, T>` ordering (lifetimes first) when `B` is spliced in. `json`
+// also exercises the generated per-field `FieldTy: FromRequest<B>` bound:
+// without it, `Json<T>: FromRequest<B>` (which needs `T: DeserializeOwned`)
+// would be unprovable for a bare, unconstrained `T`.
+#[derive(FromRequest)]
+struct Extractor<'a, T> {
+    marker: Marker<'a>,
+    json: Json<T>,
+}
+
+fn main() {}
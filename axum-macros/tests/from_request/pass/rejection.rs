@@ -0,0 +1,55 @@
+use axum::extract::{
+    rejection::{JsonRejection, QueryRejection},
+    Json, Query,
+};
+use axum::response::IntoResponse;
+use axum_macros::FromRequest;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Payload {
+    foo: String,
+}
+
+#[derive(Deserialize)]
+struct Params {
+    bar: String,
+}
+
+enum ExtractorRejection {
+    Json(JsonRejection),
+    Query(QueryRejection),
+}
+
+impl From<JsonRejection> for ExtractorRejection {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::Json(rejection)
+    }
+}
+
+impl From<QueryRejection> for ExtractorRejection {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::Query(rejection)
+    }
+}
+
+impl axum::response::IntoResponse for ExtractorRejection {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::Json(rejection) => rejection.into_response(),
+            Self::Query(rejection) => rejection.into_response(),
+        }
+    }
+}
+
+// A container-level `rejection(...)` override collapses each field's own
+// rejection into a single strongly-typed error enum via `From`, instead of
+// the default blanket `Response`.
+#[derive(FromRequest)]
+#[from_request(rejection(ExtractorRejection))]
+struct Extractor {
+    json: Json<Payload>,
+    query: Query<Params>,
+}
+
+fn main() {}
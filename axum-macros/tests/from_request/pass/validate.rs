@@ -0,0 +1,36 @@
+use axum::extract::Query;
+use axum_macros::FromRequest;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+// `validate` takes either a `fn(&T) -> bool` predicate...
+#[derive(FromRequest)]
+struct BoolValidated {
+    #[from_request(validate = |pagination: &Query<Pagination>| pagination.per_page <= 100)]
+    pagination: Query<Pagination>,
+}
+
+// ...or a `fn(&T) -> Result<(), E: IntoResponse>` predicate, for a custom
+// rejection body.
+fn validate_page(
+    pagination: &Query<Pagination>,
+) -> Result<(), (axum::http::StatusCode, &'static str)> {
+    if pagination.page == 0 {
+        Err((axum::http::StatusCode::BAD_REQUEST, "`page` must be >= 1"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(FromRequest)]
+struct ResultValidated {
+    #[from_request(validate = validate_page)]
+    pagination: Query<Pagination>,
+}
+
+fn main() {}